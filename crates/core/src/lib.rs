@@ -1,4 +1,4 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 /// Get the length of an account's data.
 pub trait DataLen {
@@ -12,9 +12,12 @@ pub trait AccountUpdates {
     fn updates(&mut self, updates: Self::Update) -> Result<(), ProgramError>;
 }
 
-/// Validate surface level account attributes like keys, data length, and more.
+/// Validate surface level account attributes like keys, data length, seeds, and more.
+///
+/// `program_id` is threaded through so PDA seed constraints can be checked
+/// against it; implementations that don't use seeds simply ignore it.
 pub trait Validate<'info> {
-    fn validate(&self) -> Result<(), ProgramError>;
+    fn validate(&self, program_id: &Pubkey) -> Result<(), ProgramError>;
 }
 
 /// Build an instruction context with both accounts and instruction data
@@ -23,6 +26,29 @@ pub trait Context<'info>: Sized {
     fn build(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError>;
 }
 
+/// An account type that carries an Anchor-compatible 8-byte discriminator as
+/// the leading bytes of its data, identifying which type the account holds.
+pub trait Discriminator {
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// Deserialize a struct of fixed-size fields out of raw instruction data,
+/// in place of hand-rolled `from_le_bytes` parsing.
+pub trait InstructionData: Sized {
+    fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError>;
+}
+
+/// Returns an error if `ptr` isn't aligned for `T`. Transmuting an
+/// under-aligned pointer into `&T`/`&mut T` is undefined behavior, so every
+/// zero-copy loader below checks this before it transmutes.
+#[inline]
+fn check_alignment<T>(ptr: *const u8) -> Result<(), ProgramError> {
+    if (ptr as usize) % core::mem::align_of::<T>() != 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
 /// Load an immutable reference to an account's data as an arbitrary type. This requires
 /// that the provided type implements the `DataLen` trait so there's assurance that
 /// no out of bounds access will occur.
@@ -46,9 +72,9 @@ pub fn load<T: DataLen>(account: &AccountInfo) -> Result<&T, ProgramError> {
     if account.data_len() != T::LEN {
         return Err(ProgramError::InvalidAccountData);
     }
-    Ok(unsafe {
-        &*core::mem::transmute::<*const u8, *const T>(account.borrow_data_unchecked().as_ptr())
-    })
+    let ptr = unsafe { account.borrow_data_unchecked().as_ptr() };
+    check_alignment::<T>(ptr)?;
+    Ok(unsafe { &*core::mem::transmute::<*const u8, *const T>(ptr) })
 }
 
 /// Load a mutable reference to an account's data as an arbitrary type. This requires
@@ -74,11 +100,28 @@ pub fn load_mut<T: DataLen>(account: &AccountInfo) -> Result<&mut T, ProgramErro
     if account.data_len() != T::LEN {
         return Err(ProgramError::InvalidAccountData);
     }
-    Ok(unsafe {
-        &mut *core::mem::transmute::<*mut u8, *mut T>(
-            account.borrow_mut_data_unchecked().as_mut_ptr(),
-        )
-    })
+    let ptr = unsafe { account.borrow_mut_data_unchecked().as_mut_ptr() };
+    check_alignment::<T>(ptr)?;
+    Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut T>(ptr) })
+}
+
+/// Load an owned copy of an account's data as an arbitrary type via an
+/// unaligned read, for callers whose account buffers aren't guaranteed to be
+/// aligned for `T`. This makes the load sound without relying on the
+/// alignment check in [`load`]/[`load_mut`] rejecting misaligned accounts.
+///
+/// # Example
+///
+/// ```rust
+/// let account_data: UserData = load_unaligned::<UserData>(&account)?;
+/// ```
+#[inline]
+pub fn load_unaligned<T: DataLen + Copy>(account: &AccountInfo) -> Result<T, ProgramError> {
+    if account.data_len() != T::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let ptr = unsafe { account.borrow_data_unchecked().as_ptr() } as *const T;
+    Ok(unsafe { core::ptr::read_unaligned(ptr) })
 }
 
 /// Extract an account's discriminator. This is useful if working with Anchor programs,
@@ -109,3 +152,71 @@ pub fn load_discriminator(
             .map_err(|_| ProgramError::InvalidAccountData)
     }
 }
+
+/// Load an immutable reference to an account's data as an arbitrary type,
+/// verifying both its length and its leading 8-byte discriminator first.
+/// This is the `Discriminator`-aware counterpart to [`load`], giving safe
+/// interop with Anchor programs that tag their accounts the same way.
+///
+/// # Example
+///
+/// ```rust
+/// let account_data = load_checked::<UserData>(&account)?;
+/// ```
+#[inline]
+pub fn load_checked<T: DataLen + Discriminator>(account: &AccountInfo) -> Result<&T, ProgramError> {
+    if account.data_len() != T::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = unsafe { account.borrow_data_unchecked() };
+    if data.len() < 8 || data[0..8] != T::DISCRIMINATOR {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let ptr = data.as_ptr();
+    check_alignment::<T>(ptr)?;
+    Ok(unsafe { &*core::mem::transmute::<*const u8, *const T>(ptr) })
+}
+
+/// Load a mutable reference to an account's data as an arbitrary type,
+/// verifying both its length and its leading 8-byte discriminator first.
+/// This is the `Discriminator`-aware counterpart to [`load_mut`].
+///
+/// # Example
+///
+/// ```rust
+/// let mut account_data = load_mut_checked::<UserData>(&account)?;
+/// ```
+#[inline]
+pub fn load_mut_checked<T: DataLen + Discriminator>(
+    account: &AccountInfo,
+) -> Result<&mut T, ProgramError> {
+    if account.data_len() != T::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = unsafe { account.borrow_mut_data_unchecked() };
+    if data.len() < 8 || data[0..8] != T::DISCRIMINATOR {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let ptr = data.as_mut_ptr();
+    check_alignment::<T>(ptr)?;
+    Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut T>(ptr) })
+}
+
+/// Write `T::DISCRIMINATOR` into the leading 8 bytes of a freshly allocated
+/// account's data. Intended to be called once, right after the account has
+/// been created and sized for `T`.
+///
+/// # Example
+///
+/// ```rust
+/// init_discriminator::<UserData>(&account)?;
+/// ```
+#[inline]
+pub fn init_discriminator<T: Discriminator>(account: &AccountInfo) -> Result<(), ProgramError> {
+    let data = unsafe { account.borrow_mut_data_unchecked() };
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    data[0..8].copy_from_slice(&T::DISCRIMINATOR);
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
+use sha2::{Digest, Sha256};
 use syn::{
     parse::Parse, parse::ParseStream, parse_macro_input, punctuated::Punctuated, Data, DeriveInput,
-    Field, Ident, Meta, Token,
+    Field, Ident, LitStr, Meta, Token,
 };
 
 /// Generates a trait implementation for `DataLen`:
@@ -13,11 +14,40 @@ use syn::{
 ///     pub const LEN: usize = core::mem::size_of::<MyStruct>();
 /// }
 /// ```
+///
+/// `load`/`load_mut` transmute an account's raw bytes directly into `&T` /
+/// `&mut T`, which is undefined behavior unless `T` has a stable, padding-free
+/// layout. To keep that path sound, this derive requires `#[repr(C)]` on the
+/// annotated type (rejecting the default Rust layout at compile time). The
+/// runtime alignment check in `load`/`load_mut` covers the rest.
 #[proc_macro_derive(DataLen)]
 pub fn derive_data_len(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
+    let is_repr_c = input.attrs.iter().any(|attr| {
+        if !attr.path().is_ident("repr") {
+            return false;
+        }
+
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                found = true;
+            }
+            Ok(())
+        });
+        found
+    });
+
+    if !is_repr_c {
+        panic!(
+            "DataLen derive requires `#[repr(C)]` on `{}` — transmuting account bytes into a \
+             type without a stable layout is undefined behavior",
+            name
+        );
+    }
+
     let expanded = quote! {
         impl pinocchio_util::DataLen for #name {
             const LEN: usize = core::mem::size_of::<#name>();
@@ -130,12 +160,32 @@ pub fn derive_updates(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// How the PDA bump should be derived for a `seeds` constraint.
+enum BumpKind {
+    /// No `bump` keyword given; the canonical bump is searched for.
+    None,
+    /// `bump` given with no value; the canonical bump is searched for and
+    /// the resulting address is compared (same as `None`, but documents intent).
+    Canonical,
+    /// `bump = <expr>` given; the bump is taken as-is and
+    /// `create_program_address` is used instead of the expensive search.
+    Explicit(syn::Expr),
+}
+
 struct ValidationAttr {
     non_empty: bool,
     is_signer: bool,
     is_executable: bool,
     len: Option<usize>,
     id: Option<syn::Expr>,
+    seeds: Option<Vec<syn::Expr>>,
+    bump: BumpKind,
+    init: bool,
+    space: Option<syn::Expr>,
+    payer: Option<syn::Ident>,
+    owner: Option<syn::Expr>,
+    close: Option<syn::Ident>,
+    discriminator: bool,
 }
 
 impl Parse for ValidationAttr {
@@ -145,6 +195,14 @@ impl Parse for ValidationAttr {
         let mut id = None;
         let mut is_signer = false;
         let mut is_executable = false;
+        let mut seeds = None;
+        let mut bump = BumpKind::None;
+        let mut init = false;
+        let mut space = None;
+        let mut payer = None;
+        let mut owner = None;
+        let mut close = None;
+        let mut discriminator = false;
 
         let args = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
 
@@ -153,6 +211,12 @@ impl Parse for ValidationAttr {
                 Meta::Path(path) => {
                     if path.is_ident("non_empty") {
                         non_empty = true;
+                    } else if path.is_ident("bump") {
+                        bump = BumpKind::Canonical;
+                    } else if path.is_ident("init") {
+                        init = true;
+                    } else if path.is_ident("discriminator") {
+                        discriminator = true;
                     }
                 }
                 Meta::NameValue(name_value) => {
@@ -166,6 +230,29 @@ impl Parse for ValidationAttr {
                         }
                     } else if name_value.path.is_ident("id") {
                         id = Some(name_value.value);
+                    } else if name_value.path.is_ident("bump") {
+                        bump = BumpKind::Explicit(name_value.value);
+                    } else if name_value.path.is_ident("seeds") {
+                        if let syn::Expr::Array(array) = &name_value.value {
+                            seeds = Some(array.elems.iter().cloned().collect());
+                        } else {
+                            return Err(syn::Error::new_spanned(
+                                &name_value.value,
+                                "expected `seeds = [..]`",
+                            ));
+                        }
+                    } else if name_value.path.is_ident("space") {
+                        space = Some(name_value.value);
+                    } else if name_value.path.is_ident("owner") {
+                        owner = Some(name_value.value);
+                    } else if name_value.path.is_ident("payer") {
+                        if let syn::Expr::Path(expr_path) = &name_value.value {
+                            payer = expr_path.path.get_ident().cloned();
+                        }
+                    } else if name_value.path.is_ident("close") {
+                        if let syn::Expr::Path(expr_path) = &name_value.value {
+                            close = expr_path.path.get_ident().cloned();
+                        }
                     }
                 }
                 _ => {}
@@ -178,19 +265,41 @@ impl Parse for ValidationAttr {
             id,
             is_signer,
             is_executable,
+            seeds,
+            bump,
+            init,
+            space,
+            payer,
+            owner,
+            close,
+            discriminator,
         })
     }
 }
 
+/// Given an expression of the form `Type::CONST` (e.g. `UserData::LEN`),
+/// returns `Type` so generated `init` code can also write its discriminator.
+fn type_from_assoc_const(expr: &syn::Expr) -> Option<syn::Type> {
+    let syn::Expr::Path(expr_path) = expr else {
+        return None;
+    };
+    let mut path = expr_path.path.clone();
+    if path.segments.len() < 2 {
+        return None;
+    }
+    path.segments.pop();
+    Some(syn::Type::Path(syn::TypePath { qself: None, path }))
+}
+
 /// Generates an implementation for `Validate`:
 ///
 /// ```rust
 /// pub trait Validate {
-///     fn validate(&self) -> Result<(), ProgramError>;
+///     fn validate(&self, program_id: &Pubkey) -> Result<(), ProgramError>;
 /// }
 ///
 /// impl Validate for MyStruct {
-///     fn validate(&self) -> Result<(), ProgramError> {
+///     fn validate(&self, program_id: &Pubkey) -> Result<(), ProgramError> {
 ///         // Validations here
 ///         Ok(())
 ///     }
@@ -209,8 +318,50 @@ impl Parse for ValidationAttr {
 ///     // Data length is 64, `field_2.key()` is the SOME_ID (Pubkey)
 ///     #[validate(len = 64, id = SOME_ID)]
 ///     field_2: &'a AccountInfo,
+///
+///     // Must be the canonical PDA for these seeds under `program_id`
+///     #[validate(seeds = [b"vault", self.field_1.key().as_ref()], bump)]
+///     field_3: &'a AccountInfo,
+///
+///     // Must match the PDA for these seeds at the given (already-known) bump
+///     #[validate(seeds = [b"vault", self.field_1.key().as_ref()], bump = self.state.bump)]
+///     field_4: &'a AccountInfo,
+/// }
+/// ```
+///
+/// Seed expressions are spliced into `validate`'s body as-is, so field
+/// references must be written as `self.field_1`, not bare.
+///
+/// `init` and `close` describe account lifecycle rather than a read-only
+/// check, so they're emitted onto a separate, generated `apply` method
+/// instead of `validate` — the processor calls it explicitly wherever it
+/// wants the account created or torn down:
+///
+/// ```rust
+/// #[derive(Context, Validate)]
+/// struct MyStruct<'info> {
+///     // `discriminator` additionally writes `UserData::DISCRIMINATOR` into
+///     // the account right after it's created, so `UserData` must also
+///     // derive `Discriminator`.
+///     #[validate(init, discriminator, space = UserData::LEN, payer = payer, owner = crate::ID)]
+///     user: &'info AccountInfo,
+///
+///     #[validate(close = destination)]
+///     stale: &'info AccountInfo,
+///
+///     #[validate(is_signer)]
+///     payer: &'info AccountInfo,
+///     destination: &'info AccountInfo,
+///     system_program: &'info AccountInfo,
 /// }
+///
+/// // ctx.apply()?;
 /// ```
+///
+/// Dropping `discriminator` skips the tag write entirely, so a plain
+/// `DataLen` type that doesn't implement `Discriminator` can be used — the
+/// write is never emitted implicitly just because `space` looks like
+/// `Type::LEN`.
 #[proc_macro_derive(Validate, attributes(validate))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -221,6 +372,8 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
         _ => panic!("This macro only supports structs"),
     };
 
+    let mut apply_stmts = Vec::new();
+
     let validation_checks: Vec<_> = fields
         .iter()
         .enumerate()
@@ -236,6 +389,65 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
             }
 
             if let Some(attr) = validation_attr {
+                if attr.init {
+                    let space = attr
+                        .space
+                        .as_ref()
+                        .expect("#[validate(init, ...)] requires `space = ...`");
+                    let payer = attr
+                        .payer
+                        .as_ref()
+                        .expect("#[validate(init, ...)] requires `payer = ...`");
+                    let owner = attr
+                        .owner
+                        .as_ref()
+                        .expect("#[validate(init, ...)] requires `owner = ...`");
+
+                    let discriminator_write = if attr.discriminator {
+                        let ty = type_from_assoc_const(space).unwrap_or_else(|| {
+                            panic!(
+                                "#[validate(init, discriminator, ...)] requires `space` to be of \
+                                 the form `Type::CONST` so the discriminator type can be inferred"
+                            )
+                        });
+                        Some(quote! {
+                            pinocchio_util::init_discriminator::<#ty>(self.#field_name)?;
+                        })
+                    } else {
+                        None
+                    };
+
+                    apply_stmts.push(quote! {
+                        let __space: usize = (#space) as usize;
+                        let __lamports = <pinocchio::sysvars::rent::Rent as pinocchio::sysvars::Sysvar>::get()?
+                            .minimum_balance(__space);
+                        pinocchio_system::instructions::CreateAccount {
+                            from: self.#payer,
+                            to: self.#field_name,
+                            lamports: __lamports,
+                            space: __space as u64,
+                            owner: &#owner,
+                        }
+                        .invoke()?;
+                        #discriminator_write
+                    });
+                }
+
+                if let Some(destination) = attr.close.as_ref() {
+                    apply_stmts.push(quote! {
+                        {
+                            let __dest_lamports = unsafe { self.#destination.borrow_mut_lamports_unchecked() };
+                            let __src_lamports = unsafe { self.#field_name.borrow_mut_lamports_unchecked() };
+                            *__dest_lamports = __dest_lamports
+                                .checked_add(*__src_lamports)
+                                .ok_or(pinocchio::program_error::ProgramError::ArithmeticOverflow)?;
+                            *__src_lamports = 0;
+                        }
+                        unsafe { self.#field_name.borrow_mut_data_unchecked() }.fill(0);
+                        unsafe { self.#field_name.assign(&pinocchio_system::ID) };
+                    });
+                }
+
                 let mut checks = Vec::new();
 
                 if attr.non_empty {
@@ -278,6 +490,28 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                     });
                 }
 
+                if let Some(seeds) = attr.seeds {
+                    let pda_check = match attr.bump {
+                        BumpKind::Explicit(bump_expr) => quote! {
+                            let __seeds: &[&[u8]] = &[#(#seeds),*, &[#bump_expr]];
+                            let __pda = pinocchio::pubkey::create_program_address(__seeds, program_id)
+                                .map_err(|_| pinocchio::program_error::ProgramError::InvalidSeeds)?;
+                            if self.#field_name.key() != &__pda {
+                                return Err(pinocchio::program_error::ProgramError::InvalidSeeds);
+                            }
+                        },
+                        BumpKind::Canonical | BumpKind::None => quote! {
+                            let __seeds: &[&[u8]] = &[#(#seeds),*];
+                            let (__pda, _bump) =
+                                pinocchio::pubkey::find_program_address(__seeds, program_id);
+                            if self.#field_name.key() != &__pda {
+                                return Err(pinocchio::program_error::ProgramError::InvalidSeeds);
+                            }
+                        },
+                    };
+                    checks.push(pda_check);
+                }
+
                 quote! {
                     #(#checks)*
                 }
@@ -289,16 +523,55 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
 
     let expanded = quote! {
         impl<'info> pinocchio_util::Validate<'info> for #name<'info> {
-            fn validate(&self) -> Result<(), pinocchio::program_error::ProgramError> {
+            fn validate(
+                &self,
+                program_id: &pinocchio::pubkey::Pubkey,
+            ) -> Result<(), pinocchio::program_error::ProgramError> {
+                let _ = program_id;
                 #(#validation_checks)*
                 Ok(())
             }
         }
+
+        impl<'info> #name<'info> {
+            /// Applies the account lifecycle mutations (`init`/`close`)
+            /// declared via `#[validate(..)]`. Unlike `validate`, this
+            /// performs CPIs and mutates account state, so the processor
+            /// must call it explicitly.
+            pub fn apply(&self) -> Result<(), pinocchio::program_error::ProgramError> {
+                #(#apply_stmts)*
+                Ok(())
+            }
+        }
     };
 
     TokenStream::from(expanded)
 }
 
+/// Returns `true` when a field should be treated as a nested sub-context
+/// rather than a single `&'info AccountInfo` leaf.
+///
+/// A field is nested if it's explicitly marked with `#[context]`, or if its
+/// type isn't a `&AccountInfo` reference (i.e. it's some other struct that
+/// itself derives `Context`).
+fn is_sub_context(field: &Field) -> bool {
+    if field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("context"))
+    {
+        return true;
+    }
+
+    match &field.ty {
+        syn::Type::Reference(reference) => !matches!(
+            &*reference.elem,
+            syn::Type::Path(path) if path.path.segments.last().map(|s| s.ident == "AccountInfo").unwrap_or(false)
+        ),
+        _ => true,
+    }
+}
+
 /// Generates an implementation for `Context`:
 ///
 /// ```rust
@@ -323,7 +596,30 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(Context)]
+///
+/// A field can also be another type that itself implements `Context<'info>`,
+/// letting contexts nest:
+///
+/// ```rust
+/// #[derive(Context, Validate)]
+/// pub struct TransferAccounts<'info> {
+///     #[validate(is_signer)]
+///     pub from: &'info AccountInfo,
+///     pub to: &'info AccountInfo,
+/// }
+///
+/// #[derive(Context, Validate)]
+/// pub struct WrappedTransferContext<'info> {
+///     pub transfer: TransferAccounts<'info>,
+///     pub authority: &'info AccountInfo,
+/// }
+/// ```
+///
+/// `ACCOUNTS_LEN` becomes the sum of each leaf field (1 account) plus each
+/// nested sub-context's own `ACCOUNTS_LEN`, and `build` slices the incoming
+/// accounts into consecutive windows, handing each sub-context its own
+/// window and recursing into its `build`.
+#[proc_macro_derive(Context, attributes(context))]
 pub fn derive_context(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -346,19 +642,40 @@ pub fn derive_context(input: TokenStream) -> TokenStream {
         _ => panic!("Context derive only works on structs"),
     };
 
-    let accounts_len = fields.len();
-    let field_assignments: Vec<_> = fields
-        .iter()
-        .enumerate()
-        .map(|(i, field)| {
-            let field_name = field.ident.as_ref().unwrap();
-            quote! { #field_name: &accounts.get_unchecked(#i), }
-        })
-        .collect();
+    let mut len_terms = Vec::new();
+    let mut build_stmts = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().unwrap();
+        field_names.push(field_name.clone());
+
+        if is_sub_context(field) {
+            let ty = &field.ty;
+            len_terms.push(quote! { <#ty as pinocchio_util::Context<'info>>::ACCOUNTS_LEN });
+            build_stmts.push(quote! {
+                let __len = <#ty as pinocchio_util::Context<'info>>::ACCOUNTS_LEN;
+                let #field_name = <#ty as pinocchio_util::Context<'info>>::build(&accounts[__offset..__offset + __len])?;
+                __offset += __len;
+            });
+        } else {
+            len_terms.push(quote! { 1 });
+            build_stmts.push(quote! {
+                let #field_name = unsafe { accounts.get_unchecked(__offset) };
+                __offset += 1;
+            });
+        }
+    }
+
+    let accounts_len_expr = if len_terms.is_empty() {
+        quote! { 0 }
+    } else {
+        quote! { #(#len_terms)+* }
+    };
 
     let expanded = quote! {
         impl<'info> pinocchio_util::Context<'info> for #name<'info> {
-            const ACCOUNTS_LEN: usize = #accounts_len;
+            const ACCOUNTS_LEN: usize = #accounts_len_expr;
 
             fn build(accounts: &'info [pinocchio::account_info::AccountInfo])
                 -> Result<Self, pinocchio::program_error::ProgramError>
@@ -367,10 +684,210 @@ pub fn derive_context(input: TokenStream) -> TokenStream {
                     return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
                 }
 
-                Ok(unsafe {
-                    Self {
-                        #(#field_assignments)*
-                    }
+                #[allow(unused_assignments)]
+                let mut __offset: usize = 0;
+                #(#build_stmts)*
+
+                Ok(Self {
+                    #(#field_names),*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generates a `Discriminator` implementation carrying an Anchor-compatible
+/// 8-byte tag: the first 8 bytes of the SHA-256 hash of a namespaced string,
+/// computed at macro-expansion time and baked into the binary as a literal.
+///
+/// The default namespaced string is `"account:<StructName>"`, matching
+/// Anchor's own convention, but it can be overridden with
+/// `#[discriminator("...")]`.
+///
+/// ```rust
+/// #[derive(Discriminator)]
+/// pub struct UserData {
+///     pub owner: Pubkey,
+/// }
+///
+/// #[derive(Discriminator)]
+/// #[discriminator("vault_v2")]
+/// pub struct VaultData {
+///     pub owner: Pubkey,
+/// }
+/// ```
+#[proc_macro_derive(Discriminator, attributes(discriminator))]
+pub fn derive_discriminator(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let preimage = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("discriminator"))
+        .map(|attr| {
+            attr.parse_args::<LitStr>()
+                .expect("expected #[discriminator(\"...\")] with a string literal")
+                .value()
+        })
+        .unwrap_or_else(|| format!("account:{}", name));
+
+    let mut hasher = Sha256::new();
+    hasher.update(preimage.as_bytes());
+    let hash = hasher.finalize();
+    let discriminator_bytes: [u8; 8] = hash[0..8].try_into().unwrap();
+
+    let expanded = quote! {
+        impl pinocchio_util::Discriminator for #name {
+            const DISCRIMINATOR: [u8; 8] = [#(#discriminator_bytes),*];
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+struct InstructionDataAttr {
+    strict: bool,
+}
+
+impl Parse for InstructionDataAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut strict = false;
+        let args = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+
+        for arg in args {
+            if let Meta::Path(path) = arg {
+                if path.is_ident("strict") {
+                    strict = true;
+                }
+            }
+        }
+
+        Ok(InstructionDataAttr { strict })
+    }
+}
+
+/// Returns the `(size, read_expr)` pair for deserializing a single fixed-size
+/// field out of `data` starting at `__cursor`.
+fn instruction_field_read(ty: &syn::Type) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let syn::Type::Path(type_path) = ty {
+        let ty_ident = &type_path.path.segments.last().unwrap().ident;
+        let ty_str = ty_ident.to_string();
+
+        return match ty_str.as_str() {
+            "bool" => (
+                quote! { 1usize },
+                quote! { data[__cursor] != 0 },
+            ),
+            "u8" => (quote! { 1usize }, quote! { data[__cursor] }),
+            "i8" => (quote! { 1usize }, quote! { data[__cursor] as i8 }),
+            "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128" | "f32" | "f64" => {
+                let size = quote! { core::mem::size_of::<#ty_ident>() };
+                let read = quote! {
+                    #ty_ident::from_le_bytes(
+                        data[__cursor..__cursor + core::mem::size_of::<#ty_ident>()]
+                            .try_into()
+                            .unwrap(),
+                    )
+                };
+                (size, read)
+            }
+            "Pubkey" => (
+                quote! { 32usize },
+                quote! {
+                    <[u8; 32]>::try_from(&data[__cursor..__cursor + 32]).unwrap()
+                },
+            ),
+            other => panic!("InstructionData derive: unsupported field type `{}`", other),
+        };
+    }
+
+    if let syn::Type::Array(array) = ty {
+        let elem = &array.elem;
+        let len = &array.len;
+        let size = quote! { (#len) };
+        let read = quote! {
+            <[#elem; #len]>::try_from(&data[__cursor..__cursor + (#len)]).unwrap()
+        };
+        return (size, read);
+    }
+
+    panic!(
+        "InstructionData derive only supports fixed-size integer, bool, Pubkey and [u8; N] fields"
+    );
+}
+
+/// Generates an `InstructionData` implementation that walks a byte slice
+/// with a running cursor, reading each field as little-endian and returning
+/// `ProgramError::InvalidInstructionData` if the slice runs out early:
+///
+/// ```rust
+/// #[derive(InstructionData)]
+/// pub struct Basic {
+///     pub amount: u64,
+///     pub recipient: Pubkey,
+/// }
+/// ```
+///
+/// By default, trailing bytes after the last field are ignored. Add
+/// `#[instruction_data(strict)]` to reject any instruction data with extra
+/// trailing bytes.
+#[proc_macro_derive(InstructionData, attributes(instruction_data))]
+pub fn derive_instruction_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("instruction_data"))
+        .map(|attr| attr.parse_args::<InstructionDataAttr>().unwrap())
+        .unwrap_or(InstructionDataAttr { strict: false });
+
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => panic!("InstructionData derive only works on structs"),
+    };
+
+    let mut field_stmts = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().unwrap();
+        field_names.push(field_name.clone());
+
+        let (size, read_expr) = instruction_field_read(&field.ty);
+        field_stmts.push(quote! {
+            let __size = #size;
+            if data.len() - __cursor < __size {
+                return Err(pinocchio::program_error::ProgramError::InvalidInstructionData);
+            }
+            let #field_name = #read_expr;
+            __cursor += __size;
+        });
+    }
+
+    let trailing_check = if attr.strict {
+        quote! {
+            if __cursor != data.len() {
+                return Err(pinocchio::program_error::ProgramError::InvalidInstructionData);
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let expanded = quote! {
+        impl pinocchio_util::InstructionData for #name {
+            fn try_from_bytes(data: &[u8]) -> Result<Self, pinocchio::program_error::ProgramError> {
+                let mut __cursor: usize = 0;
+                #(#field_stmts)*
+                #trailing_check
+
+                Ok(Self {
+                    #(#field_names),*
                 })
             }
         }
@@ -378,3 +895,47 @@ pub fn derive_context(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Attribute macro that runs one or more guard expressions before the body
+/// of an instruction handler, short-circuiting on the first `Err`:
+///
+/// ```rust
+/// #[access_control(is_authorized(&params), amount_in_bounds(params.data.amount))]
+/// pub fn handle(params: Self) -> ProgramResult {
+///     // only reached once every guard above has returned Ok(())
+///     ...
+/// }
+/// ```
+///
+/// Each guard is any expression evaluating to `Result<(), ProgramError>` —
+/// typically a call to a free function or another associated function.
+/// Guards run in declaration order, before any state mutation or CPI in the
+/// function body, giving a single reusable place for authority and
+/// precondition checks instead of scattering `if !ok { return Err(...) }`
+/// through the handler. Composes with `Validate`-derived checks, which the
+/// handler can still call explicitly.
+#[proc_macro_attribute]
+pub fn access_control(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let guards =
+        parse_macro_input!(attr with Punctuated::<syn::Expr, Token![,]>::parse_terminated);
+    let mut func = parse_macro_input!(item as syn::ItemFn);
+
+    let guard_checks: Vec<_> = guards
+        .iter()
+        .map(|guard| {
+            quote! {
+                #guard?;
+            }
+        })
+        .collect();
+
+    let original_block = &func.block;
+    func.block = Box::new(syn::parse_quote! {
+        {
+            #(#guard_checks)*
+            #original_block
+        }
+    });
+
+    TokenStream::from(quote! { #func })
+}
@@ -1,7 +1,7 @@
 #![allow(unused)]
 
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
-use pinocchio_derive::{Context, DataLen, Updates, Validate};
+use pinocchio_derive::{Context, DataLen, Discriminator, Updates, Validate};
 use pinocchio_log::log;
 use pinocchio_pubkey::pubkey;
 
@@ -9,7 +9,13 @@ use crate::error::ProcessError;
 
 const RANDOM_ID: Pubkey = pubkey!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt");
 
-#[derive(DataLen, Updates)]
+/// A fixed bump for the `vault_meta` PDA below, sidestepping the
+/// canonical-bump search. The seeds include the payer's key, so validation
+/// passes only where that payer's `vault_meta` address exists at this bump.
+const VAULT_META_BUMP: u8 = 254;
+
+#[repr(C)]
+#[derive(Clone, Copy, DataLen, Updates)]
 pub struct IWantToBeARealBoy {
     pub discriminator: [u8; 8],
     pub data: [u8; 32],
@@ -38,13 +44,63 @@ impl IWantToBeARealBoy {
 }
 
 #[derive(Context, Validate)]
-pub struct BasicContext<'info> {
+pub struct TransferAccounts<'info> {
     #[validate(is_signer)]
     pub from: &'info AccountInfo,
 
     #[validate(id = RANDOM_ID)]
     pub to: &'info AccountInfo,
+}
+
+/// Wraps `TransferAccounts` as a nested sub-context alongside a plain leaf
+/// field, so `Context`'s `build` has to window accounts across both a
+/// recursive sub-context call and a direct `get_unchecked`.
+#[derive(Context, Validate)]
+pub struct BasicContext<'info> {
+    pub transfer: TransferAccounts<'info>,
 
     #[validate(is_executable, id = pinocchio_system::ID)]
     pub system_program: &'info AccountInfo,
 }
+
+/// An account tagged with an Anchor-compatible discriminator, created and
+/// authenticated via [`CreateVaultContext`].
+#[repr(C)]
+#[derive(DataLen, Updates, Discriminator)]
+pub struct VaultData {
+    pub discriminator: [u8; 8],
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(Context, Validate)]
+pub struct CreateVaultContext<'info> {
+    #[validate(is_signer)]
+    pub payer: &'info AccountInfo,
+
+    /// Created fresh on `apply()`, tagged with `VaultData::DISCRIMINATOR`,
+    /// and checked against its canonical PDA in `validate()`.
+    #[validate(
+        init,
+        discriminator,
+        space = VaultData::LEN,
+        payer = payer,
+        owner = crate::ID,
+        seeds = [b"vault", self.payer.key().as_ref()],
+        bump
+    )]
+    pub vault: &'info AccountInfo,
+
+    /// A second PDA checked against an already-known bump, exercising the
+    /// `create_program_address` arm instead of the canonical-bump search.
+    #[validate(seeds = [b"vault_meta", self.payer.key().as_ref()], bump = VAULT_META_BUMP)]
+    pub vault_meta: &'info AccountInfo,
+
+    #[validate(close = destination)]
+    pub stale: &'info AccountInfo,
+    pub destination: &'info AccountInfo,
+
+    /// Read with `load_unaligned`, which returns an owned copy of the data
+    /// rather than a reference into it as `load`/`load_mut` do.
+    pub real_boy: &'info AccountInfo,
+}
@@ -1,12 +1,20 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pinocchio::pubkey::Pubkey;
+use pinocchio_derive::InstructionData;
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 pub enum ProgramInstruction {
     Basic = 0,
+    CreateVault = 1,
 }
 
-#[derive(Clone)]
+#[derive(Clone, InstructionData)]
 pub struct BasicInstruction {
     pub amount: u64,
 }
+
+#[derive(Clone, InstructionData)]
+pub struct CreateVaultInstruction {
+    pub authority: Pubkey,
+}
@@ -1,10 +1,16 @@
 #![allow(unused, clippy::all, clippy::as_ptr_cast_mut)]
 
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
 mod context;
 mod error;
 mod instructions;
 mod processor;
 
+/// This program's own address, used as the `owner` of accounts it creates.
+pub const ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
 #[cfg(not(feature = "bpf-entrypoint"))]
 pub mod entrypoint {
     use pinocchio::{
@@ -13,12 +19,16 @@ pub mod entrypoint {
     };
     use pinocchio_log::log;
 
-    use crate::{error::ProcessError, instructions::ProgramInstruction, processor::Basic};
+    use crate::{
+        error::ProcessError,
+        instructions::ProgramInstruction,
+        processor::{Basic, CreateVault},
+    };
 
     entrypoint!(process_instruction);
 
     pub fn process_instruction(
-        _program_id: &Pubkey,
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
         instruction_data: &[u8],
     ) -> ProgramResult {
@@ -30,9 +40,14 @@ pub mod entrypoint {
         match ProgramInstruction::try_from(*discriminator) {
             Ok(ProgramInstruction::Basic) => {
                 log!("Instruction: Basic");
-                let params = Basic::load(accounts, data)?;
+                let params = Basic::load(program_id, accounts, data)?;
                 Basic::handle(params)?;
             }
+            Ok(ProgramInstruction::CreateVault) => {
+                log!("Instruction: CreateVault");
+                let params = CreateVault::load(program_id, accounts, data)?;
+                CreateVault::handle(params)?;
+            }
             _ => {
                 log!("Invalid instruction");
                 return Err(ProcessError::InvalidInstruction.into());
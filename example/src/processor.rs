@@ -1,11 +1,14 @@
 use pinocchio::account_info::AccountInfo;
 use pinocchio::program_error::ProgramError;
+use pinocchio::pubkey::Pubkey;
 use pinocchio::ProgramResult;
+use pinocchio_derive::access_control;
 use pinocchio_log::log;
 use pinocchio_system::instructions::Transfer;
-use pinocchio_util::Context;
+use pinocchio_util::{load_mut_checked, load_unaligned, Context, InstructionData, Validate};
 
 use crate::context::*;
+use crate::error::ProcessError;
 use crate::instructions::*;
 
 pub struct Basic<'info> {
@@ -14,22 +17,29 @@ pub struct Basic<'info> {
 }
 
 impl<'info> Basic<'info> {
-    pub fn load(accounts: &'info [AccountInfo], data: &[u8]) -> Result<Self, ProgramError> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo],
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
         let ctx = BasicContext::build(accounts).map_err(|_| ProgramError::InvalidArgument)?;
-        let amount = u64::from_le_bytes(data.try_into().unwrap());
+        ctx.transfer.validate(program_id)?;
+        ctx.validate(program_id)?;
+        let data = BasicInstruction::try_from_bytes(data)?;
 
         Ok(Self {
             accounts: ctx,
-            data: BasicInstruction { amount },
+            data,
         })
     }
 
+    #[access_control(ensure_non_zero_amount(params.data.amount))]
     pub fn handle(params: Self) -> ProgramResult {
         log!("I want to be a real boy!");
 
         Transfer {
-            from: params.accounts.from,
-            to: params.accounts.to,
+            from: params.accounts.transfer.from,
+            to: params.accounts.transfer.to,
             lamports: params.data.amount,
         }
         .invoke()?;
@@ -37,3 +47,48 @@ impl<'info> Basic<'info> {
         Ok(())
     }
 }
+
+fn ensure_non_zero_amount(amount: u64) -> Result<(), ProgramError> {
+    if amount == 0 {
+        return Err(ProcessError::InvalidInstruction.into());
+    }
+
+    Ok(())
+}
+
+pub struct CreateVault<'info> {
+    pub accounts: CreateVaultContext<'info>,
+    pub data: CreateVaultInstruction,
+}
+
+impl<'info> CreateVault<'info> {
+    pub fn load(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo],
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        let ctx =
+            CreateVaultContext::build(accounts).map_err(|_| ProgramError::InvalidArgument)?;
+        ctx.validate(program_id)?;
+        let data = CreateVaultInstruction::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts: ctx,
+            data,
+        })
+    }
+
+    pub fn handle(params: Self) -> ProgramResult {
+        log!("Creating vault");
+
+        params.accounts.apply()?;
+
+        let vault = load_mut_checked::<VaultData>(params.accounts.vault)?;
+        vault.authority = params.data.authority;
+        vault.bump = 0;
+
+        let _real_boy = load_unaligned::<IWantToBeARealBoy>(params.accounts.real_boy)?;
+
+        Ok(())
+    }
+}